@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use std::fmt;
+use std::{fmt, sync::Arc};
 
 /// An error describing appliance failures.
 pub enum Error<M> {
@@ -12,6 +12,12 @@ pub enum Error<M> {
     Timeout,
     /// Indicates that an error at this point is unexpected.
     UnexpectedFailure(Option<M>),
+    /// Indicates that the appliance's event loop has terminated after a handler
+    /// panicked. Every caller observing the same underlying crash, whether it was
+    /// already waiting on a reply or sends only after the fact, receives the same
+    /// `Arc`-wrapped `PanicInfo`, along with the message back if the failure was
+    /// observed on a send (waiters on an already in-flight reply have none to give).
+    Closed(Arc<PanicInfo>, Option<M>),
 }
 
 impl<M> fmt::Debug for Error<M> {
@@ -20,6 +26,7 @@ impl<M> fmt::Debug for Error<M> {
             Error::Timeout => write!(f, "Timeout"),
             Error::FullBuffer(..) => write!(f, "FullBuffer(..)"),
             Error::UnexpectedFailure(..) => write!(f, "UnexpectedFailure(..)"),
+            Error::Closed(info, ..) => write!(f, "Closed({:?}, ..)", info),
         }
     }
 }
@@ -30,8 +37,41 @@ impl<M> fmt::Display for Error<M> {
             Error::Timeout => write!(f, "send timeout is exceeded"),
             Error::FullBuffer(..) => write!(f, "appliance buffer is full"),
             Error::UnexpectedFailure(..) => write!(f, "unexpected failure"),
+            Error::Closed(info, ..) => write!(f, "appliance is closed: {}", info),
         }
     }
 }
 
 impl<M> std::error::Error for Error<M> {}
+
+/// Information captured from a panic raised inside a `Handler::handle` implementation.
+///
+/// Once an appliance's event loop observes a handler panic, it stores one of these in a
+/// slot shared with every `ApplianceHandle`, turning a crash into a diagnosable failure
+/// instead of a silent disconnect.
+pub struct PanicInfo {
+    message: String,
+}
+
+impl PanicInfo {
+    pub(crate) fn new(payload: Box<dyn std::any::Any + Send>) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "appliance handler panicked".to_string());
+        PanicInfo { message }
+    }
+}
+
+impl fmt::Debug for PanicInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PanicInfo({})", self.message)
+    }
+}
+
+impl fmt::Display for PanicInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
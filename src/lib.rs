@@ -5,12 +5,13 @@
 mod error;
 
 use std::{
-    any::type_name,
+    any::{type_name, Any},
     fmt::{self, Debug},
-    panic::catch_unwind,
-    sync::{Arc, Weak},
+    panic::{catch_unwind, AssertUnwindSafe},
+    marker::PhantomData,
+    sync::{Arc, Mutex, Weak},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 use {
     async_io::block_on,
@@ -19,7 +20,7 @@ use {
     once_cell::sync::Lazy,
 };
 
-pub use crate::error::Error;
+pub use crate::error::{Error, PanicInfo};
 
 /// An async executor.
 pub type Executor<'a> = async_executor::Executor<'a>;
@@ -53,15 +54,15 @@ pub static DEFAULT_EXECUTOR: Lazy<Executor<'static>> = Lazy::new(|| {
 /// impl Message for Ping { type Result = usize; }
 ///
 /// impl Handler<Ping> for Counter {
-///     fn handle(&mut self, _msg: &Ping) -> usize {
+///     fn handle(&mut self, _msg: Ping) -> usize {
 ///         *self.state() += 1;
 ///         *self.state()
 ///     }
 /// }
 ///
 /// fn do_ping(handle: ApplianceHandle<Counter>) {
-///     match handle.send_and_wait_with_timeout(Ping, Duration::from_secs(10)) {
-///         Ok(cnt) => println!("Appliance was pinged successfully {} times", *cnt),
+///     match handle.send_and_wait_sync(Ping, Some(Duration::from_secs(10))) {
+///         Ok(cnt) => println!("Appliance was pinged successfully {} times", cnt),
 ///         Err(err) => panic!("Ping to appliance has failed: {}", err),
 ///     }
 /// }
@@ -80,8 +81,7 @@ pub trait Message: Send {
 ///
 /// # Example
 /// ```
-/// # use std::time::Duration;
-/// # use appliance::{Appliance, ApplianceHandle, DEFAULT_EXECUTOR, Handler, Message};
+/// # use appliance::{Appliance, DEFAULT_EXECUTOR, Handler, Message};
 /// type Counter = Appliance<'static, usize>;
 ///
 /// struct Ping;
@@ -89,7 +89,7 @@ pub trait Message: Send {
 /// impl Message for Ping { type Result = usize; }
 ///
 /// impl Handler<Ping> for Counter {
-///     fn handle(&mut self, _msg: &Ping) -> usize {
+///     fn handle(&mut self, _msg: Ping) -> usize {
 ///         *self.state() += 1;
 ///         *self.state()
 ///     }
@@ -100,19 +100,19 @@ pub trait Message: Send {
 /// impl Message for Reset { type Result = (); }
 ///
 /// impl Handler<Reset> for Counter {
-///     fn handle(&mut self, _msg: &Reset) {
+///     fn handle(&mut self, _msg: Reset) {
 ///         *self.state() = 0;
 ///     }
 /// }
 ///
 /// const BUF_SIZE: usize = 10;
 ///
-/// fn main() -> Result<(), appliance::Error> {
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let handle = Appliance::new_bounded(&DEFAULT_EXECUTOR, 0, BUF_SIZE);
-///     assert_eq!(*handle.send_and_wait_sync(Ping)?, 1);
-///     assert_eq!(*handle.send_and_wait_sync(Ping)?, 2);
-///     handle.send(Reset)?;
-///     assert_eq!(*handle.send_and_wait_sync(Ping)?, 1);
+///     assert_eq!(handle.send_and_wait_sync(Ping, None)?, 1);
+///     assert_eq!(handle.send_and_wait_sync(Ping, None)?, 2);
+///     handle.send_sync(Reset)?;
+///     assert_eq!(handle.send_and_wait_sync(Ping, None)?, 1);
 ///     Ok(())
 /// }
 /// ```
@@ -152,28 +152,96 @@ where
     }
 }
 
+/// A trait which must be implemented for appliances which are intended to process
+/// messages of type `M` in batches rather than one at a time. See `Appliance::new_batched`.
+///
+/// Unlike `Handler`, which is invoked once per message, `handle_batch` is invoked once
+/// per accumulated batch, with the batch flushed whenever it reaches `max_items` messages
+/// or `max_latency` has elapsed since the first message of the batch arrived.
+pub trait BatchHandler<M: Message> {
+    /// Handle a batch of messages accumulated since the previous flush.
+    fn handle_batch(&mut self, msgs: Vec<M>);
+}
+
+/// A message together with the channel its result, if any, is reported back on.
+///
+/// Kept as its own type (rather than folded directly into the `InnerMessage` closure)
+/// so that a send failure can hand the original `M` back to the caller by downcasting
+/// the still-boxed envelope, instead of discarding it inside an already-built closure.
+struct Envelope<M: Message> {
+    message: M,
+    reply_channel: Option<Sender<M::Result>>,
+}
+
+/// Type-erased dispatch step for an `InnerMessage`: downcasts its boxed `Envelope<M>`
+/// back to the concrete type and runs it against the handler, returning `false` if the
+/// handler panicked (and `true` otherwise) so the caller's event loop knows to stop.
+/// Named mainly to keep clippy's `type_complexity` lint happy on the `InnerMessage`
+/// field below.
+type Dispatch<'a, H> = Box<dyn FnOnce(Box<dyn Any + Send>, &mut H) -> bool + Send + 'a>;
+
 struct InnerMessage<'a, H: ?Sized + 'a> {
-    handle_message: Box<dyn FnOnce(&mut H) + Send + 'a>,
+    envelope: Box<dyn Any + Send>,
+    dispatch: Dispatch<'a, H>,
 }
 
 impl<'a, H: ?Sized + 'a> InnerMessage<'a, H> {
-    fn new<M>(message: M, reply_channel: Option<Sender<M::Result>>) -> Self
+    /// Builds a new envelope for `message`. The message must be `'static` so that the
+    /// envelope can be downcast back to `Envelope<M>`, both to dispatch it and to
+    /// recover `M` if the send that carries it fails.
+    ///
+    /// `poison` is the slot the handling appliance poisons on a handler panic. It is
+    /// written to from inside `dispatch`, before `reply_channel` is dropped, so that a
+    /// caller already blocked on `reply_channel`'s disconnect can never observe that
+    /// disconnect before the poison slot that explains it.
+    fn new<M>(message: M, reply_channel: Option<Sender<M::Result>>, poison: Poison) -> Self
     where
-        M: HandledBy<H> + 'a,
+        M: HandledBy<H> + 'static,
     {
         InnerMessage {
-            handle_message: Box::new(move |handler| {
-                let result = message.handle_by(handler);
+            envelope: Box::new(Envelope {
+                message,
+                reply_channel,
+            }),
+            dispatch: Box::new(move |envelope, handler| {
+                let Envelope {
+                    message,
+                    reply_channel,
+                } = *envelope
+                    .downcast::<Envelope<M>>()
+                    .expect("InnerMessage envelope type mismatch");
+                let result = match catch_unwind(AssertUnwindSafe(|| message.handle_by(handler))) {
+                    Ok(result) => result,
+                    Err(payload) => {
+                        *poison.lock().unwrap() = Some(Arc::new(PanicInfo::new(payload)));
+                        return false;
+                    }
+                };
                 if let Some(rc) = &reply_channel {
                     rc.send(result).ok();
                 }
+                true
             }),
         }
     }
+
+    /// Recovers the original message from a send that failed to enqueue this envelope,
+    /// discarding its reply channel since the caller already knows the send failed.
+    fn into_message<M: Message + 'static>(self) -> Option<M> {
+        self.envelope.downcast::<Envelope<M>>().ok().map(|e| e.message)
+    }
 }
 
 type MessageSender<'a, H> = Sender<InnerMessage<'a, H>>;
 
+/// A slot shared between an appliance's event loop and all of its handles, populated
+/// once with the panic that terminated the loop, if any.
+type Poison = Arc<Mutex<Option<Arc<PanicInfo>>>>;
+
+/// A single-slot shared cell backing a coalescing appliance. `send_latest` overwrites
+/// whatever is currently in the slot instead of queueing behind it.
+type Slot<'a, H> = Arc<Mutex<Option<InnerMessage<'a, H>>>>;
+
 /// A stateful entity that only allows to interact with via handling messages.
 ///
 /// The appliance itself is not directly available. Messages must be sent to it
@@ -184,6 +252,7 @@ type MessageSender<'a, H> = Sender<InnerMessage<'a, H>>;
 pub struct Appliance<'s, S> {
     state: S,
     handle: Weak<MessageSender<'s, Self>>,
+    poison: Poison,
 }
 
 impl<'s, S: Debug + 's> Debug for Appliance<'s, S> {
@@ -214,6 +283,195 @@ where
         Self::run(executor, state, None)
     }
 
+    /// Creates a new throttling appliance whose event loop wakes at most once per
+    /// `quantum`, draining every message currently queued in a single burst before
+    /// sleeping again, instead of waking once per message.
+    ///
+    /// This trades up to `quantum` of latency for a large reduction in task wakeups and
+    /// scheduler churn under bursty, high-frequency load.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// # use appliance::{Appliance, Handler, Message, DEFAULT_EXECUTOR};
+    /// type Counter = Appliance<'static, usize>;
+    ///
+    /// struct Ping;
+    ///
+    /// impl Message for Ping { type Result = usize; }
+    ///
+    /// impl Handler<Ping> for Counter {
+    ///     fn handle(&mut self, _msg: Ping) -> usize {
+    ///         *self.state() += 1;
+    ///         *self.state()
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let handle =
+    ///         Appliance::new_throttled(&DEFAULT_EXECUTOR, 0, 10, Duration::from_millis(5));
+    ///     assert_eq!(handle.send_and_wait_sync(Ping, None)?, 1);
+    ///     assert_eq!(handle.send_and_wait_sync(Ping, None)?, 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_throttled(
+        executor: &'s Executor<'s>,
+        state: S,
+        size: usize,
+        quantum: Duration,
+    ) -> ApplianceHandle<'s, Self> {
+        let (in_, out_) = bounded(size);
+        let poison: Poison = Arc::new(Mutex::new(None));
+        let handle = ApplianceHandle {
+            inner: Arc::new(in_),
+            poison: poison.clone(),
+        };
+        let mut appliance = Appliance {
+            state,
+            handle: Arc::downgrade(&handle.inner),
+            poison,
+        };
+        executor
+            .spawn(async move { appliance.handle_throttled(out_, quantum).await })
+            .detach();
+        handle
+    }
+
+    /// Creates a new batching appliance which accumulates incoming messages of a single
+    /// type `M` and flushes them to `BatchHandler::handle_batch` in one call, instead of
+    /// invoking a handler once per message.
+    ///
+    /// A batch is flushed as soon as it reaches `max_items` messages, or `max_latency` has
+    /// elapsed since the first message of the batch arrived, whichever happens first. This
+    /// amortizes per-message handling overhead for high-throughput workloads.
+    ///
+    /// Unlike `new_bounded`/`new_unbounded`, a batching appliance is dedicated to a single
+    /// message type; use the returned `BatchApplianceHandle` to send it messages.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{sync::mpsc, time::Duration};
+    /// # use appliance::{Appliance, BatchHandler, Message, DEFAULT_EXECUTOR};
+    /// type Batcher = Appliance<'static, mpsc::Sender<Vec<usize>>>;
+    ///
+    /// struct Item(usize);
+    ///
+    /// impl Message for Item { type Result = (); }
+    ///
+    /// impl BatchHandler<Item> for Batcher {
+    ///     fn handle_batch(&mut self, msgs: Vec<Item>) {
+    ///         self.state().send(msgs.into_iter().map(|Item(v)| v).collect()).ok();
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let (tx, rx) = mpsc::channel();
+    ///     let handle =
+    ///         Appliance::new_batched(&DEFAULT_EXECUTOR, tx, 10, 2, Duration::from_millis(200));
+    ///
+    ///     // Flushed because the batch reached `max_items` (2).
+    ///     handle.send_sync(Item(1))?;
+    ///     handle.send_sync(Item(2))?;
+    ///     assert_eq!(rx.recv_timeout(Duration::from_secs(1))?, vec![1, 2]);
+    ///
+    ///     // Flushed because `max_latency` elapsed with only one message queued.
+    ///     handle.send_sync(Item(3))?;
+    ///     assert_eq!(rx.recv_timeout(Duration::from_secs(1))?, vec![3]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_batched<M>(
+        executor: &'s Executor<'s>,
+        state: S,
+        size: usize,
+        max_items: usize,
+        max_latency: Duration,
+    ) -> BatchApplianceHandle<'s, M>
+    where
+        M: Message + Send + 's,
+        Self: BatchHandler<M>,
+    {
+        let (in_, out_) = bounded(size);
+        let poison: Poison = Arc::new(Mutex::new(None));
+        let handle = BatchApplianceHandle {
+            inner: Arc::new(in_),
+            poison: poison.clone(),
+            _marker: PhantomData,
+        };
+        let mut appliance = Appliance {
+            state,
+            handle: Weak::new(),
+            poison,
+        };
+        executor
+            .spawn(async move { appliance.handle_batches(out_, max_items, max_latency).await })
+            .detach();
+        handle
+    }
+
+    /// Creates a new coalescing appliance backed by a single-slot shared cell instead of
+    /// a FIFO buffer: a newly sent message overwrites any still-unprocessed prior value
+    /// rather than queueing behind it.
+    ///
+    /// This suits state where only the newest update matters (sensor readings, progress
+    /// ticks, config snapshots), guaranteeing bounded memory regardless of producer rate
+    /// and ensuring a slow handler always jumps to the most recent message rather than
+    /// working through a stale backlog.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{sync::mpsc, time::Duration};
+    /// # use appliance::{Appliance, Handler, Message, DEFAULT_EXECUTOR};
+    /// type Reporter = Appliance<'static, mpsc::Sender<usize>>;
+    ///
+    /// struct Reading(usize);
+    ///
+    /// impl Message for Reading { type Result = (); }
+    ///
+    /// impl Handler<Reading> for Reporter {
+    ///     fn handle(&mut self, Reading(value): Reading) {
+    ///         self.state().send(value).ok();
+    ///     }
+    /// }
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let (tx, rx) = mpsc::channel();
+    ///     let handle = Appliance::new_latest(&DEFAULT_EXECUTOR, tx);
+    ///     handle.send_latest(Reading(1))?;
+    ///     handle.send_latest(Reading(2))?;
+    ///     handle.send_latest(Reading(3))?;
+    ///     // Readings 1 and 2 may or may not show up individually, depending on how
+    ///     // quickly the handler drains the slot, but 3 is always the last one handled
+    ///     // since nothing overwrites it afterwards.
+    ///     let mut last = rx.recv_timeout(Duration::from_secs(1))?;
+    ///     while let Ok(value) = rx.recv_timeout(Duration::from_millis(100)) {
+    ///         last = value;
+    ///     }
+    ///     assert_eq!(last, 3);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_latest(executor: &'s Executor<'s>, state: S) -> LatestApplianceHandle<'s, Self> {
+        let slot: Slot<'s, Self> = Arc::new(Mutex::new(None));
+        let (doorbell_in, doorbell_out) = bounded(1);
+        let poison: Poison = Arc::new(Mutex::new(None));
+        let handle = LatestApplianceHandle {
+            slot: slot.clone(),
+            doorbell: doorbell_in,
+            poison: poison.clone(),
+        };
+        let mut appliance = Appliance {
+            state,
+            handle: Weak::new(),
+            poison,
+        };
+        executor
+            .spawn(async move { appliance.handle_latest(slot, doorbell_out).await })
+            .detach();
+        handle
+    }
+
     /// Creates a new appliance with the given state, message handler,
     /// and buffer size, if any.
     fn run(
@@ -226,12 +484,15 @@ where
         } else {
             unbounded()
         };
+        let poison: Poison = Arc::new(Mutex::new(None));
         let handle = ApplianceHandle {
             inner: Arc::new(in_),
+            poison: poison.clone(),
         };
         let mut appliance = Appliance {
             state,
             handle: Arc::downgrade(&handle.inner),
+            poison,
         };
         executor
             .spawn(async move { appliance.handle_messages(out_).await })
@@ -246,7 +507,10 @@ where
     /// This function will return `None` if all appliance handles were already dropped.
     /// In this case the appliance must shutdown.
     pub fn handle(&'s self) -> Option<ApplianceHandle<'s, Self>> {
-        self.handle.upgrade().map(|inner| ApplianceHandle { inner })
+        self.handle.upgrade().map(|inner| ApplianceHandle {
+            inner,
+            poison: self.poison.clone(),
+        })
     }
 
     /// The mutable inner state of the appliance.
@@ -260,9 +524,122 @@ where
     }
 
     async fn handle_messages(&mut self, out_: Receiver<InnerMessage<'_, Self>>) {
-        while let Ok(InnerMessage { handle_message }) = out_.recv_async().await {
-            handle_message(self);
+        while let Ok(InnerMessage { envelope, dispatch }) = out_.recv_async().await {
+            if !dispatch(envelope, self) {
+                break;
+            }
+        }
+    }
+
+    async fn handle_latest(&mut self, slot: Slot<'_, Self>, doorbell: Receiver<()>) {
+        while doorbell.recv_async().await.is_ok() {
+            let next = slot.lock().unwrap().take();
+            let Some(InnerMessage { envelope, dispatch }) = next else {
+                continue;
+            };
+            if !dispatch(envelope, self) {
+                break;
+            }
+        }
+    }
+
+    async fn handle_throttled(&mut self, out_: Receiver<InnerMessage<'_, Self>>, quantum: Duration) {
+        while let Ok(first) = out_.recv_async().await {
+            async_io::Timer::after(quantum).await;
+            let mut burst = vec![first];
+            while let Ok(msg) = out_.try_recv() {
+                burst.push(msg);
+            }
+            for InnerMessage { envelope, dispatch } in burst {
+                if !dispatch(envelope, self) {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn handle_batches<M>(&mut self, out_: Receiver<M>, max_items: usize, max_latency: Duration)
+    where
+        M: Message + Send,
+        Self: BatchHandler<M>,
+    {
+        enum Next<M> {
+            Msg(M),
+            TimedOut,
+            Closed,
+        }
+
+        let mut batch: Vec<M> = Vec::new();
+        let mut deadline: Option<Instant> = None;
+        loop {
+            let next = match deadline {
+                Some(dl) => {
+                    or(
+                        async {
+                            match out_.recv_async().await {
+                                Ok(msg) => Next::Msg(msg),
+                                Err(_) => Next::Closed,
+                            }
+                        },
+                        async {
+                            async_io::Timer::at(dl).await;
+                            Next::TimedOut
+                        },
+                    )
+                    .await
+                }
+                None => match out_.recv_async().await {
+                    Ok(msg) => Next::Msg(msg),
+                    Err(_) => Next::Closed,
+                },
+            };
+            match next {
+                Next::Msg(msg) => {
+                    if batch.is_empty() {
+                        deadline = Some(Instant::now() + max_latency);
+                    }
+                    batch.push(msg);
+                    while batch.len() < max_items {
+                        match out_.try_recv() {
+                            Ok(msg) => batch.push(msg),
+                            Err(_) => break,
+                        }
+                    }
+                    if batch.len() >= max_items {
+                        deadline = None;
+                        if !self.flush_batch(std::mem::take(&mut batch)) {
+                            return;
+                        }
+                    }
+                }
+                Next::TimedOut => {
+                    deadline = None;
+                    if !self.flush_batch(std::mem::take(&mut batch)) {
+                        return;
+                    }
+                }
+                Next::Closed => break,
+            }
+        }
+        if !batch.is_empty() {
+            self.flush_batch(batch);
+        }
+    }
+
+    /// Invokes `BatchHandler::handle_batch` with the same panic isolation as the other
+    /// event loops: on a caught panic, records it in the shared poison slot and returns
+    /// `false` so the caller stops draining instead of continuing against a possibly
+    /// corrupted appliance.
+    fn flush_batch<M>(&mut self, batch: Vec<M>) -> bool
+    where
+        M: Message,
+        Self: BatchHandler<M>,
+    {
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| self.handle_batch(batch))) {
+            *self.poison.lock().unwrap() = Some(Arc::new(PanicInfo::new(payload)));
+            return false;
         }
+        true
     }
 }
 
@@ -282,6 +659,7 @@ pub struct ApplianceHandle<'a, A: ?Sized> {
     /// happens, the appliance will stay alive after all handles are dropped, which violates the
     /// API contract.
     inner: Arc<MessageSender<'a, A>>,
+    poison: Poison,
 }
 
 impl<'a, A: ?Sized + 'a> Debug for ApplianceHandle<'a, A> {
@@ -294,34 +672,54 @@ impl<'a, A: ?Sized + 'a> Clone for ApplianceHandle<'a, A> {
     fn clone(&self) -> Self {
         ApplianceHandle {
             inner: self.inner.clone(),
+            poison: self.poison.clone(),
         }
     }
 }
 
 impl<'a, A: ?Sized + 'a> ApplianceHandle<'a, A> {
+    /// Returns the appliance's closed-with-panic error if its event loop has terminated
+    /// after a handler panicked, or the generic `UnexpectedFailure` otherwise, carrying
+    /// `message` back if it is available.
+    fn closed_or_unexpected<M>(&self, message: Option<M>) -> Error<M> {
+        match &*self.poison.lock().unwrap() {
+            Some(info) => Error::Closed(info.clone(), message),
+            None => Error::UnexpectedFailure(message),
+        }
+    }
+
     /// Sends a message to the current appliance without
     /// waiting for the message to be handled.
-    pub fn send_sync<M>(&self, message: M) -> Result<(), Error>
+    ///
+    /// `M` must be `'static`: if the appliance's buffer is full or its event loop has
+    /// terminated (whether cleanly disconnected or closed after a handler panic), the
+    /// unsent message is handed back via `Error::FullBuffer`/`Error::UnexpectedFailure`/
+    /// `Error::Closed` so the caller can retry, reroute, or log it.
+    pub fn send_sync<M>(&self, message: M) -> Result<(), Error<M>>
     where
-        M: HandledBy<A> + 'a,
+        M: HandledBy<A> + 'static,
     {
-        match self.inner.try_send(InnerMessage::new(message, None)) {
+        match self.inner.try_send(InnerMessage::new(message, None, self.poison.clone())) {
             Ok(_) => Ok(()),
-            Err(TrySendError::Full(_)) => Err(Error::FullBuffer),
-            Err(TrySendError::Disconnected(_)) => Err(Error::UnexpectedFailure),
+            Err(TrySendError::Full(inner)) => Err(Error::FullBuffer(
+                inner.into_message().expect("InnerMessage envelope type mismatch"),
+            )),
+            Err(TrySendError::Disconnected(inner)) => {
+                Err(self.closed_or_unexpected(inner.into_message()))
+            }
         }
     }
 
     /// Does conceptually the same thing as `send_sync` but gets intended
     /// to be used in async context.
-    pub async fn send_async<M>(&self, message: M) -> Result<(), Error>
+    pub async fn send_async<M>(&self, message: M) -> Result<(), Error<M>>
     where
-        M: HandledBy<A> + 'a,
+        M: HandledBy<A> + 'static,
     {
         self.inner
-            .send_async(InnerMessage::new(message, None))
+            .send_async(InnerMessage::new(message, None, self.poison.clone()))
             .await
-            .map_err(|_| Error::UnexpectedFailure)
+            .map_err(|e| self.closed_or_unexpected(e.0.into_message()))
     }
 
     /// Sends a message to the current appliance and waits
@@ -337,24 +735,30 @@ impl<'a, A: ?Sized + 'a> ApplianceHandle<'a, A> {
         &self,
         message: M,
         timeout: Option<Duration>,
-    ) -> Result<M::Result, Error>
+    ) -> Result<M::Result, Error<M>>
     where
-        M: HandledBy<A> + 'a,
+        M: HandledBy<A> + 'static,
     {
         let (s, r) = bounded(1);
-        match self.inner.try_send(InnerMessage::new(message, Some(s))) {
-            Err(TrySendError::Full(_)) => return Err(Error::FullBuffer),
-            Err(TrySendError::Disconnected(_)) => return Err(Error::UnexpectedFailure),
+        match self.inner.try_send(InnerMessage::new(message, Some(s), self.poison.clone())) {
+            Err(TrySendError::Full(inner)) => {
+                return Err(Error::FullBuffer(
+                    inner.into_message().expect("InnerMessage envelope type mismatch"),
+                ))
+            }
+            Err(TrySendError::Disconnected(inner)) => {
+                return Err(self.closed_or_unexpected(inner.into_message()))
+            }
             _ => {}
         }
         if let Some(timeout) = timeout {
             match r.recv_timeout(timeout) {
                 Ok(r) => Ok(r),
                 Err(RecvTimeoutError::Timeout) => Err(Error::Timeout),
-                Err(RecvTimeoutError::Disconnected) => Err(Error::UnexpectedFailure),
+                Err(RecvTimeoutError::Disconnected) => Err(self.closed_or_unexpected(None)),
             }
         } else {
-            r.recv().map_err(|_| Error::UnexpectedFailure)
+            r.recv().map_err(|_| self.closed_or_unexpected(None))
         }
     }
 
@@ -365,23 +769,23 @@ impl<'a, A: ?Sized + 'a> ApplianceHandle<'a, A> {
         &self,
         message: M,
         timeout: Option<Duration>,
-    ) -> Result<M::Result, Error>
+    ) -> Result<M::Result, Error<M>>
     where
-        M: HandledBy<A> + 'a,
+        M: HandledBy<A> + 'static,
     {
         let (send, recv) = bounded(1);
-        if let Err(_) = self
+        if let Err(e) = self
             .inner
-            .send_async(InnerMessage::new(message, Some(send)))
+            .send_async(InnerMessage::new(message, Some(send), self.poison.clone()))
             .await
         {
-            return Err(Error::UnexpectedFailure);
+            return Err(self.closed_or_unexpected(e.0.into_message()));
         }
         if let Some(timeout) = timeout {
             let f1 = async {
                 recv.recv_async()
                     .await
-                    .map_err(|_| Error::UnexpectedFailure)
+                    .map_err(|_| self.closed_or_unexpected(None))
             };
             let f2 = async {
                 async_io::Timer::after(timeout).await;
@@ -391,7 +795,246 @@ impl<'a, A: ?Sized + 'a> ApplianceHandle<'a, A> {
         } else {
             recv.recv_async()
                 .await
-                .map_err(|_| Error::UnexpectedFailure)
+                .map_err(|_| self.closed_or_unexpected(None))
+        }
+    }
+}
+
+/// A handle to a batching appliance created via `Appliance::new_batched`.
+///
+/// Unlike `ApplianceHandle`, this handle is dedicated to a single message type `M` and
+/// does not support waiting for a reply, since messages are processed together in
+/// batches rather than individually.
+///
+/// The `'a` parameter mirrors `ApplianceHandle`'s API shape (tying the handle to the
+/// executor's lifetime), even though the handle itself holds no reference to the executor.
+pub struct BatchApplianceHandle<'a, M> {
+    inner: Arc<Sender<M>>,
+    poison: Poison,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a, M> Debug for BatchApplianceHandle<'a, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BatchApplianceHandle<{}>(..)", type_name::<M>())
+    }
+}
+
+impl<'a, M> Clone for BatchApplianceHandle<'a, M> {
+    fn clone(&self) -> Self {
+        BatchApplianceHandle {
+            inner: self.inner.clone(),
+            poison: self.poison.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, M: Send + 'a> BatchApplianceHandle<'a, M> {
+    /// Returns the appliance's closed-with-panic error if its event loop has terminated
+    /// after a handler panicked, or the generic `UnexpectedFailure` otherwise, carrying
+    /// `message` back if it is available.
+    fn closed_or_unexpected(&self, message: Option<M>) -> Error<M> {
+        match &*self.poison.lock().unwrap() {
+            Some(info) => Error::Closed(info.clone(), message),
+            None => Error::UnexpectedFailure(message),
+        }
+    }
+
+    /// Sends a message to the current batching appliance without waiting
+    /// for its batch to be flushed. On failure, the unsent message is handed
+    /// back via `Error::FullBuffer`/`Error::UnexpectedFailure`/`Error::Closed`.
+    pub fn send_sync(&self, message: M) -> Result<(), Error<M>> {
+        match self.inner.try_send(message) {
+            Ok(_) => Ok(()),
+            Err(TrySendError::Full(m)) => Err(Error::FullBuffer(m)),
+            Err(TrySendError::Disconnected(m)) => Err(self.closed_or_unexpected(Some(m))),
+        }
+    }
+
+    /// Does conceptually the same thing as `send_sync` but gets intended
+    /// to be used in async context.
+    pub async fn send_async(&self, message: M) -> Result<(), Error<M>> {
+        self.inner
+            .send_async(message)
+            .await
+            .map_err(|e| self.closed_or_unexpected(Some(e.0)))
+    }
+}
+
+struct Subscriber<'a, A: ?Sized> {
+    sender: Weak<MessageSender<'a, A>>,
+    poison: Poison,
+}
+
+/// A pub/sub broadcaster that fans a cloned copy of each published message out to every
+/// subscribed appliance.
+///
+/// Unlike `ApplianceHandle`, which is strictly point-to-point, `Broadcast` delivers one
+/// message to every live subscriber. Subscribing does not keep a subscriber's appliance
+/// alive: it is tracked via a weak reference, matching `ApplianceHandle`'s existing
+/// lifetime contract, so once all of an appliance's handles are dropped, `publish`
+/// silently prunes it from the subscriber set.
+///
+/// # Example
+/// ```
+/// # use appliance::{Appliance, Broadcast, Handler, Message, DEFAULT_EXECUTOR};
+/// type Counter = Appliance<'static, usize>;
+///
+/// #[derive(Clone)]
+/// struct Tick;
+///
+/// impl Message for Tick { type Result = usize; }
+///
+/// impl Handler<Tick> for Counter {
+///     fn handle(&mut self, _msg: Tick) -> usize {
+///         *self.state() += 1;
+///         *self.state()
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let a = Appliance::new_unbounded(&DEFAULT_EXECUTOR, 0);
+///     let b = Appliance::new_unbounded(&DEFAULT_EXECUTOR, 0);
+///     let broadcast: Broadcast<Counter, Tick> = Broadcast::new();
+///     broadcast.subscribe(&a);
+///     broadcast.subscribe(&b);
+///     assert!(broadcast.publish(Tick).is_empty());
+///     // `a`'s event loop is FIFO, so by the time this reply comes back the
+///     // broadcast `Tick` sent just above has already been handled too.
+///     assert_eq!(a.send_and_wait_sync(Tick, None)?, 2);
+///     Ok(())
+/// }
+/// ```
+pub struct Broadcast<'a, A: ?Sized, M> {
+    subscribers: Mutex<Vec<Subscriber<'a, A>>>,
+    _message: PhantomData<M>,
+}
+
+impl<'a, A: ?Sized + 'a, M> Debug for Broadcast<'a, A, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Broadcast<{}>(..)", type_name::<A>())
+    }
+}
+
+impl<'a, A: ?Sized + 'a, M> Broadcast<'a, A, M> {
+    /// Creates an empty broadcaster with no subscribers.
+    pub fn new() -> Self {
+        Broadcast {
+            subscribers: Mutex::new(Vec::new()),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<'a, A: ?Sized + 'a, M> Default for Broadcast<'a, A, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: ?Sized + 'a, M> Broadcast<'a, A, M>
+where
+    M: HandledBy<A> + Clone + 'static,
+{
+    /// Subscribes an appliance to this broadcaster.
+    pub fn subscribe(&self, handle: &ApplianceHandle<'a, A>) {
+        self.subscribers.lock().unwrap().push(Subscriber {
+            sender: Arc::downgrade(&handle.inner),
+            poison: handle.poison.clone(),
+        });
+    }
+
+    /// Publishes a clone of `message` to every live subscriber, fire-and-forget, like
+    /// `ApplianceHandle::send_sync`. Subscribers whose appliance has since been dropped are
+    /// silently pruned. Returns the `FullBuffer`/`UnexpectedFailure`/`Closed` outcome for
+    /// every subscriber that is still alive but failed to accept the message; results can't
+    /// be collapsed across heterogeneous subscribers, so a successful delivery contributes
+    /// nothing to the returned `Vec`.
+    pub fn publish(&self, message: M) -> Vec<Error<M>> {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let mut errors = Vec::new();
+        subscribers.retain(|subscriber| match subscriber.sender.upgrade() {
+            None => false,
+            Some(inner) => {
+                let handle = ApplianceHandle {
+                    inner,
+                    poison: subscriber.poison.clone(),
+                };
+                if let Err(err) = handle.send_sync(message.clone()) {
+                    errors.push(err);
+                }
+                true
+            }
+        });
+        errors
+    }
+}
+
+/// A handle to a coalescing appliance created via `Appliance::new_latest`.
+///
+/// Unlike `ApplianceHandle`, sending through this handle never queues behind a prior
+/// unprocessed message: `send_latest` simply overwrites it.
+pub struct LatestApplianceHandle<'a, A: ?Sized> {
+    slot: Slot<'a, A>,
+    doorbell: Sender<()>,
+    poison: Poison,
+}
+
+impl<'a, A: ?Sized + 'a> Debug for LatestApplianceHandle<'a, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LatestApplianceHandle<{}>(..)", type_name::<A>())
+    }
+}
+
+impl<'a, A: ?Sized + 'a> Clone for LatestApplianceHandle<'a, A> {
+    fn clone(&self) -> Self {
+        LatestApplianceHandle {
+            slot: self.slot.clone(),
+            doorbell: self.doorbell.clone(),
+            poison: self.poison.clone(),
+        }
+    }
+}
+
+impl<'a, A: ?Sized + 'a> LatestApplianceHandle<'a, A> {
+    /// Returns the appliance's closed-with-panic error if its event loop has terminated
+    /// after a handler panicked, or the generic `UnexpectedFailure` otherwise, carrying
+    /// `message` back if it is available.
+    fn closed_or_unexpected<M>(&self, message: Option<M>) -> Error<M> {
+        match &*self.poison.lock().unwrap() {
+            Some(info) => Error::Closed(info.clone(), message),
+            None => Error::UnexpectedFailure(message),
+        }
+    }
+
+    /// Sends a message to the current appliance, overwriting whatever message, if any,
+    /// is still waiting to be handled.
+    ///
+    /// Unlike `ApplianceHandle::send_sync`, this can't fail with `Error::FullBuffer`:
+    /// there is no buffer to fill, only a single slot to overwrite. It still fails with
+    /// `Error::Closed` once the appliance's event loop has terminated after a panic.
+    pub fn send_latest<M>(&self, message: M) -> Result<(), Error<M>>
+    where
+        M: HandledBy<A> + 'static,
+    {
+        self.slot
+            .lock()
+            .unwrap()
+            .replace(InnerMessage::new(message, None, self.poison.clone()));
+        match self.doorbell.try_send(()) {
+            Ok(_) | Err(TrySendError::Full(_)) => Ok(()),
+            Err(TrySendError::Disconnected(_)) => {
+                // The event loop is gone, so the slot we just wrote to is orphaned;
+                // take the message back out instead of leaving it stranded there.
+                let recovered = self
+                    .slot
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .and_then(InnerMessage::into_message);
+                Err(self.closed_or_unexpected(recovered))
+            }
         }
     }
 }